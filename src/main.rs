@@ -1,6 +1,9 @@
 extern crate chrono;
 extern crate clap;
 extern crate ctrlc;
+extern crate dirs;
+extern crate notify_rust;
+extern crate signal_hook;
 extern crate termion;
 
 use chrono::DateTime;
@@ -10,6 +13,18 @@ use chrono::Local;
 use clap::App;
 use clap::Arg;
 
+use std::collections::HashMap;
+
+use std::fs;
+use std::fs::OpenOptions;
+
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+
+use std::path::Path;
+use std::path::PathBuf;
+
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -17,6 +32,19 @@ use std::sync::atomic::Ordering;
 use std::thread;
 use std::thread::park_timeout;
 
+use termion::raw::IntoRawMode;
+use termion::screen::AlternateScreen;
+
+use signal_hook::consts::{SIGCONT, SIGTSTP, SIGWINCH};
+use signal_hook::iterator::Signals;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Phase {
+    Work,
+    ShortBreak,
+    LongBreak
+}
+
 #[derive(Clone, Debug)]
 struct State<'a> {
     width: u16,
@@ -25,13 +53,28 @@ struct State<'a> {
     end: DateTime<Local>,
     task: Option<&'a str>,
     duration: Duration,
-    remaining: Duration
+    remaining: Duration,
+    phase: Phase,
+    completed_work: u32,
+    work_duration: Duration,
+    short_break_duration: Duration,
+    long_break_duration: Duration,
+    cycles: u32,
+    paused_at: Option<DateTime<Local>>,
+    total_paused: Duration,
+    notify: bool,
+    bell: bool,
+    running: bool,
+    log_path: PathBuf
 }
 
 enum Event {
     WindowSizeChange(u16, u16),
     CtrlC,
-    Timeout(DateTime<Local>)
+    Timeout(DateTime<Local>),
+    PauseToggle(DateTime<Local>),
+    Skip(DateTime<Local>),
+    Quit
 }
 
 fn main() {
@@ -43,12 +86,45 @@ fn main() {
                                .long("task")
                                .help("Display the specified task on the timer.  This will help keep you focused.")
                                .takes_value(true))
-                        .arg(Arg::with_name("duration")
-                               .long("duration")
-                               .help("Specify the duration of the pomodoro.  Defaults to 25m.")
+                        .arg(Arg::with_name("work")
+                               .long("work")
+                               .help("Specify the duration of a work interval.  Defaults to 25m.")
+                               .takes_value(true))
+                        .arg(Arg::with_name("short-break")
+                               .long("short-break")
+                               .help("Specify the duration of a short break.  Defaults to 5m.")
+                               .takes_value(true))
+                        .arg(Arg::with_name("long-break")
+                               .long("long-break")
+                               .help("Specify the duration of a long break.  Defaults to 15m.")
+                               .takes_value(true))
+                        .arg(Arg::with_name("cycles")
+                               .long("cycles")
+                               .help("Specify the number of work intervals between long breaks.  Defaults to 4.")
                                .takes_value(true))
+                        .arg(Arg::with_name("notify")
+                               .long("notify")
+                               .help("Show a desktop notification when a work or break interval ends."))
+                        .arg(Arg::with_name("bell")
+                               .long("bell")
+                               .help("Ring the terminal bell when a work or break interval ends."))
+                        .arg(Arg::with_name("log")
+                               .long("log")
+                               .help("Override the path to the session history log.  Defaults to a file under the user's data directory.")
+                               .takes_value(true))
+                        .arg(Arg::with_name("summary")
+                               .long("summary")
+                               .help("Print a summary of today's completed pomodoros from the history log, then exit."))
                         .get_matches();
 
+    let log_path = matches.value_of("log").map(PathBuf::from).unwrap_or_else(default_log_path);
+
+    if matches.is_present("summary") {
+        print_summary(&log_path);
+
+        return;
+    }
+
     // Setup a CTRL-C handler so we can cleanly close.  This is basically ensuring we reset the colours and cursor.
     let keep_running_in_handler = Arc::new(AtomicBool::new(true));
     let keep_running = keep_running_in_handler.clone();
@@ -59,13 +135,52 @@ fn main() {
         current_thread.unpark();
     }).unwrap();
 
+    // SIGWINCH tells us the terminal was resized, SIGTSTP/SIGCONT bracket a Ctrl-Z
+    // suspend/resume. Mirror the ctrlc handler above: flip a flag and unpark the main
+    // thread instead of polling terminal_size() every tick.
+    let resized = Arc::new(AtomicBool::new(false));
+    let suspend_requested = Arc::new(AtomicBool::new(false));
+    let resumed = Arc::new(AtomicBool::new(false));
+
+    let resized_in_handler = resized.clone();
+    let suspend_in_handler = suspend_requested.clone();
+    let resumed_in_handler = resumed.clone();
+    let signal_thread = thread::current();
+
+    let mut signals = Signals::new([SIGWINCH, SIGTSTP, SIGCONT]).unwrap();
+    thread::spawn(move || {
+        for signal in signals.forever() {
+            match signal {
+                SIGWINCH => resized_in_handler.store(true, Ordering::SeqCst),
+                SIGTSTP => suspend_in_handler.store(true, Ordering::SeqCst),
+                SIGCONT => resumed_in_handler.store(true, Ordering::SeqCst),
+                _ => {}
+            }
+            signal_thread.unpark();
+        }
+    });
 
-    let mut last_width = 0;
-    let mut last_height = 0;
+    // Raw mode disables line-buffering/echo so keypresses reach us immediately, and the
+    // alternate screen keeps our drawing off the user's real scrollback. `screen` is boxed
+    // so a SIGTSTP can swap it out for a plain stdout (actually leaving the alternate screen
+    // and raw mode by dropping the guard) before the process really suspends.
+    let mut screen: Box<dyn Write> = Box::new(AlternateScreen::from(std::io::stdout().into_raw_mode().unwrap()));
+    let mut stdin_bytes = BufReader::new(termion::async_stdin()).bytes();
 
     let (width, height) = termion::terminal_size().unwrap_or((80, 24));
 
-    let mut state = match initialize_state(width, height, matches.value_of("task"), matches.value_of("duration")) {
+    let mut state = match initialize_state(StateConfig {
+        width,
+        height,
+        task: matches.value_of("task"),
+        work: matches.value_of("work"),
+        short_break: matches.value_of("short-break"),
+        long_break: matches.value_of("long-break"),
+        cycles: matches.value_of("cycles"),
+        notify: matches.is_present("notify"),
+        bell: matches.is_present("bell"),
+        log_path
+    }) {
         Ok(x) => x,
         Err(error_msg) => {
             println!("{}", error_msg);
@@ -73,123 +188,419 @@ fn main() {
             return;
         }
     };
-        
+
+    draw_all(&state, &mut screen);
+
     // Update the screen.
-    while Local::now() < state.end && keep_running.load(Ordering::SeqCst) {
-        let (width, height) = termion::terminal_size().unwrap_or((80, 24));
+    while keep_running.load(Ordering::SeqCst) && state.running {
+        if suspend_requested.swap(false, Ordering::SeqCst) {
+            restore_terminal(&mut screen);
+
+            // Drop the alternate-screen/raw-mode guard so the shell gets back a normal,
+            // cooked terminal before we actually stop.
+            screen = Box::new(std::io::stdout());
+
+            let _ = signal_hook::low_level::emulate_default_handler(SIGTSTP);
+            // Execution resumes here once the shell sends SIGCONT.
+        }
+
+        if resumed.swap(false, Ordering::SeqCst) {
+            screen = Box::new(AlternateScreen::from(std::io::stdout().into_raw_mode().unwrap()));
+
+            let (width, height) = termion::terminal_size().unwrap_or((80, 24));
+            state.width = width;
+            state.height = height;
+
+            draw_all(&state, &mut screen);
+        }
+
+        if resized.swap(false, Ordering::SeqCst) {
+            let (width, height) = termion::terminal_size().unwrap_or((80, 24));
+            handle_event(Event::WindowSizeChange(width, height), &mut state, &mut screen);
+        }
 
-        if width != last_width || height != last_height {
-            handle_event(Event::WindowSizeChange(width, height), &mut state);
-            last_width = width;
-            last_height = height;
+        while let Some(Ok(byte)) = stdin_bytes.next() {
+            match byte {
+                b' ' | b'p' => handle_event(Event::PauseToggle(Local::now()), &mut state, &mut screen),
+                b's' => handle_event(Event::Skip(Local::now()), &mut state, &mut screen),
+                b'q' => handle_event(Event::Quit, &mut state, &mut screen),
+                _ => {}
+            }
         }
 
-        handle_event(Event::Timeout(Local::now()), &mut state);
-        
+        handle_event(Event::Timeout(Local::now()), &mut state, &mut screen);
+
         if !keep_running.load(Ordering::SeqCst) {
-            handle_event(Event::CtrlC, &mut state);
+            handle_event(Event::CtrlC, &mut state, &mut screen);
         }
 
-        if state.remaining.num_seconds() > 120 { 
-            // Update less frequently if we have a ways to go.
+        if state.remaining.num_seconds() > 120 {
+            // Update less frequently if we have a ways to go; a resize or suspend unparks us early.
             park_timeout(std::time::Duration::from_secs(10));
         } else {
-            park_timeout(std::time::Duration::from_secs(1));            
+            park_timeout(std::time::Duration::from_secs(1));
         }
     }
 
+    // One teardown routine for every way out of the loop: Ctrl-C and `q` both land here
+    // (the phases repeat, so the loop has no other end).
+    restore_terminal(&mut screen);
+}
+
+fn parse_duration_arg(duration: Option<&str>, default_duration: &str) -> Result<Duration, String> {
+    let duration_str = duration.unwrap_or(default_duration);
+    let human_duration = humantime::parse_duration(duration_str);
+    if human_duration.is_err() {
+        return Err(format!("'{}' is not a valid duration.", duration_str));
+    }
+
+    Ok(Duration::from_std(human_duration.unwrap()).unwrap())
 }
 
-fn initialize_state<'a>(width:u16, height:u16, task:Option<&'a str>, duration:Option<&'a str>) -> Result<State<'a>, String> {
+fn parse_cycles_arg(cycles: Option<&str>) -> Result<u32, String> {
+    let cycles_str = cycles.unwrap_or("4");
+    match cycles_str.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("'{}' is not a valid number of cycles.", cycles_str))
+    }
+}
+
+fn default_log_path() -> PathBuf {
+    dirs::data_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("romodoro")
+        .join("history.csv")
+}
+
+// Groups up the arg-parsed values `initialize_state` needs, so the call site can't silently
+// transpose two adjacent `bool`s or `Option<&str>`s the way a long positional argument list can.
+struct StateConfig<'a> {
+    width: u16,
+    height: u16,
+    task: Option<&'a str>,
+    work: Option<&'a str>,
+    short_break: Option<&'a str>,
+    long_break: Option<&'a str>,
+    cycles: Option<&'a str>,
+    notify: bool,
+    bell: bool,
+    log_path: PathBuf
+}
+
+fn initialize_state(config: StateConfig) -> Result<State, String> {
     let start = Local::now();
-    let dur = match duration {
-        Some(duration_str) => {
-            let human_duration = humantime::parse_duration(duration_str);
-            if human_duration.is_err() {
-                return Err(format!("'{}' is not a valid duration.", duration_str));
-            }
 
-            Duration::from_std(human_duration.unwrap()).unwrap()
-        },
-        None => Duration::seconds(60 * 25)
-    };
+    let work_duration = parse_duration_arg(config.work, "25m")?;
+    let short_break_duration = parse_duration_arg(config.short_break, "5m")?;
+    let long_break_duration = parse_duration_arg(config.long_break, "15m")?;
+    let cycles = parse_cycles_arg(config.cycles)?;
 
     Ok(State {
         start,
-        end: start + dur,
-        task,
-        duration: dur,
-        width,
-        height,
-        remaining: (start + dur) - start
+        end: start + work_duration,
+        task: config.task,
+        duration: work_duration,
+        width: config.width,
+        height: config.height,
+        remaining: work_duration,
+        phase: Phase::Work,
+        completed_work: 0,
+        work_duration,
+        short_break_duration,
+        long_break_duration,
+        cycles,
+        paused_at: None,
+        total_paused: Duration::zero(),
+        notify: config.notify,
+        bell: config.bell,
+        running: true,
+        log_path: config.log_path
     })
 }
 
 #[cfg(test)]
 #[test]
 fn initialize_state_tests() {
-    let state = initialize_state(20, 80, None, Some("15m"));
+    let state = initialize_state(StateConfig { width: 20, height: 80, task: None, work: Some("15m"), short_break: None, long_break: None, cycles: None, notify: false, bell: false, log_path: default_log_path() });
     assert_eq!(true, state.is_ok());
 
-    let state = initialize_state(20, 80, None, Some("15"));
+    let state = initialize_state(StateConfig { width: 20, height: 80, task: None, work: Some("15"), short_break: None, long_break: None, cycles: None, notify: false, bell: false, log_path: default_log_path() });
     assert_eq!(false, state.is_ok());
     assert_eq!("'15' is not a valid duration.", state.unwrap_err());
+
+    let state = initialize_state(StateConfig { width: 20, height: 80, task: None, work: None, short_break: None, long_break: None, cycles: Some("0"), notify: false, bell: false, log_path: default_log_path() });
+    assert_eq!(false, state.is_ok());
+}
+
+fn next_phase(current: &Phase, completed_work: u32, cycles: u32) -> Phase {
+    match current {
+        Phase::Work => {
+            if completed_work.is_multiple_of(cycles) {
+                Phase::LongBreak
+            } else {
+                Phase::ShortBreak
+            }
+        },
+        Phase::ShortBreak | Phase::LongBreak => Phase::Work
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn next_phase_tests() {
+    assert_eq!(Phase::ShortBreak, next_phase(&Phase::Work, 1, 4));
+    assert_eq!(Phase::ShortBreak, next_phase(&Phase::Work, 3, 4));
+    assert_eq!(Phase::LongBreak, next_phase(&Phase::Work, 4, 4));
+    assert_eq!(Phase::Work, next_phase(&Phase::ShortBreak, 1, 4));
+    assert_eq!(Phase::Work, next_phase(&Phase::LongBreak, 4, 4));
+}
+
+fn phase_duration(state: &State) -> Duration {
+    match state.phase {
+        Phase::Work => state.work_duration,
+        Phase::ShortBreak => state.short_break_duration,
+        Phase::LongBreak => state.long_break_duration
+    }
+}
+
+fn phase_label<'a>(state: &'a State) -> &'a str {
+    match state.phase {
+        Phase::Work => state.task.unwrap_or("Work"),
+        Phase::ShortBreak => "Short Break",
+        Phase::LongBreak => "Long Break"
+    }
+}
+
+fn phase_name(phase: &Phase) -> &'static str {
+    match phase {
+        Phase::Work => "Work",
+        Phase::ShortBreak => "Short Break",
+        Phase::LongBreak => "Long Break"
+    }
+}
+
+fn phase_complete_summary(phase: &Phase) -> &'static str {
+    match phase {
+        Phase::Work => "Work complete",
+        Phase::ShortBreak => "Short break complete",
+        Phase::LongBreak => "Long break complete"
+    }
+}
+
+fn phase_started_body(state: &State) -> String {
+    match state.phase {
+        Phase::Work => format!("Back to work for {}m", state.duration.num_minutes()),
+        Phase::ShortBreak => format!("Take a {}m break", state.duration.num_minutes()),
+        Phase::LongBreak => format!("Take a {}m long break", state.duration.num_minutes())
+    }
 }
 
-fn draw_screen_reset() {
-    // Revert the cursor, colours and style.
-    println!("{}", termion::cursor::Show);
-    println!("{}", termion::color::Fg(termion::color::Reset));
-    println!("{}", termion::style::Reset);
-} 
+fn alert_phase_change(state: &State, finished_phase: &Phase, screen: &mut dyn Write) {
+    if !state.notify && !state.bell {
+        return;
+    }
+
+    if state.notify {
+        // Ignore failures: headless sessions or a missing notification daemon shouldn't crash the timer.
+        let _ = notify_rust::Notification::new()
+            .summary(phase_complete_summary(finished_phase))
+            .body(&phase_started_body(state))
+            .show();
+    }
 
-fn handle_event(event: Event, state:&mut State) {
+    if state.bell {
+        write!(screen, "\x07").unwrap();
+        let _ = screen.flush();
+    }
+}
+
+fn write_history_entry(path: &Path, start: DateTime<Local>, end: DateTime<Local>, duration: Duration, phase: &Phase, task: Option<&str>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(file, "{},{},{},{},{}",
+        start.format("%Y-%m-%d %H:%M:%S"),
+        end.format("%Y-%m-%d %H:%M:%S"),
+        duration.num_seconds(),
+        phase_name(phase),
+        task.unwrap_or(""))
+}
+
+#[cfg(test)]
+#[test]
+fn write_history_entry_round_trip_test() {
+    let path = std::env::temp_dir().join(format!("romodoro_test_history_{}.csv", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let start = Local::now();
+    let duration = Duration::seconds(24 * 60 + 50);
+
+    write_history_entry(&path, start, start + duration, duration, &Phase::Work, Some("writing")).unwrap();
+
+    let contents = fs::read_to_string(&path).unwrap();
+    let fields: Vec<&str> = contents.trim().splitn(5, ',').collect();
+
+    assert_eq!(5, fields.len());
+    assert_eq!("Work", fields[3]);
+    assert_eq!("writing", fields[4]);
+    assert_eq!(25, duration_minutes(Duration::seconds(fields[2].parse().unwrap())));
+
+    let _ = fs::remove_file(&path);
+}
+
+fn advance_phase(state: &mut State, now: DateTime<Local>, screen: &mut dyn Write) {
+    let finished_phase = state.phase.clone();
+    let finished_start = state.start;
+
+    if state.phase == Phase::Work {
+        state.completed_work += 1;
+    }
+
+    // Best effort: a logging failure (e.g. an unwritable path) shouldn't interrupt the timer.
+    let actual_duration = (now - finished_start) - state.total_paused;
+    let _ = write_history_entry(&state.log_path, finished_start, now, actual_duration, &finished_phase, state.task);
+
+    state.phase = next_phase(&state.phase, state.completed_work, state.cycles);
+    state.duration = phase_duration(state);
+    state.start = now;
+    state.end = now + state.duration;
+    state.remaining = state.duration;
+    state.total_paused = Duration::zero();
+    state.paused_at = None;
+
+    alert_phase_change(state, &finished_phase, screen);
+
+    draw_all(state, screen);
+}
+
+#[cfg(test)]
+#[test]
+fn advance_phase_clears_pause_on_skip_test() {
+    let log_path = std::env::temp_dir().join(format!("romodoro_test_advance_phase_{}.csv", std::process::id()));
+    let _ = fs::remove_file(&log_path);
+
+    let mut state = initialize_state(StateConfig { width: 20, height: 80, task: None, work: Some("15m"), short_break: None, long_break: None, cycles: None, notify: false, bell: false, log_path: log_path.clone() }).unwrap();
+    let mut screen: Vec<u8> = Vec::new();
+
+    let now = Local::now();
+    state.paused_at = Some(now);
+
+    // Skipping while paused must not carry the pause into the next phase: a stale
+    // `paused_at` would freeze the new phase's countdown until the next pause-toggle and corrupt that
+    // pause-toggle's `paused_for` calculation against the old timestamp.
+    advance_phase(&mut state, now, &mut screen);
+
+    assert_eq!(None, state.paused_at);
+
+    let _ = fs::remove_file(&log_path);
+}
+
+fn restore_terminal(screen: &mut dyn Write) {
+    // Revert the cursor, colours and style. Leaving the alternate screen itself happens
+    // when `screen` is dropped, restoring the user's real scrollback untouched.
+    write!(screen, "{}", termion::cursor::Show).unwrap();
+    write!(screen, "{}", termion::color::Fg(termion::color::Reset)).unwrap();
+    write!(screen, "{}", termion::style::Reset).unwrap();
+    let _ = screen.flush();
+}
+
+fn handle_event(event: Event, state:&mut State, screen: &mut dyn Write) {
     match event {
         Event::WindowSizeChange(w, h) => {
             state.width = w;
             state.height = h;
 
-            draw_all(state);
+            draw_all(state, screen);
+        },
+        Event::CtrlC => {
+            state.running = false;
         },
-        Event::CtrlC => { 
-            draw_screen_reset();
-            std::process::exit(0);
+        Event::Quit => {
+            state.running = false;
         },
         Event::Timeout(now) => {
-            if now > state.end {
-                draw_screen_reset();
-                std::process::exit(0);
-            } 
-            state.remaining = state.end - now;
+            if state.paused_at.is_some() {
+                // Remaining is frozen while paused.
+            } else if now > state.end {
+                advance_phase(state, now, screen);
+            } else {
+                state.remaining = state.end - now;
+            }
+        },
+        Event::PauseToggle(now) => {
+            match state.paused_at {
+                Some(paused_since) => {
+                    // Shift end forward by however long we were paused so the countdown
+                    // resumes where it left off, and track the gap separately so it can be
+                    // subtracted back out of the logged duration (start stays unshifted —
+                    // it's still the "Start:" display value).
+                    let paused_for = now - paused_since;
+                    state.end += paused_for;
+                    state.total_paused += paused_for;
+                    state.paused_at = None;
+                },
+                None => {
+                    state.paused_at = Some(now);
+                }
+            }
+        },
+        Event::Skip(now) => {
+            advance_phase(state, now, screen);
         }
     };
 
-    draw_changes(state);
-}
-
-fn draw_all(state:&State) {
-    
-    println!("{}", termion::clear::All);
-    
-    // Print Task
-    if state.task.is_some() {
-        let task_str = &state.task.unwrap();
-        println!("{}{}{}{}{}{}", 
-            termion::style::Bold,
-            termion::color::Fg(termion::color::LightRed),
-            termion::cursor::Goto((state.width / 2) - (task_str.len() / 2) as u16, state.height / 2), 
-            &state.task.unwrap(),
-            termion::color::Fg(termion::color::Reset),
-            termion::style::Reset);
+    if state.running {
+        draw_changes(state, screen);
+    }
+}
+
+fn draw_all(state:&State, screen: &mut dyn Write) {
+
+    writeln!(screen, "{}", termion::clear::All).unwrap();
+
+    // Print the task (during work) or the current phase name (during breaks).
+    let label = phase_label(state);
+    match state.phase {
+        Phase::Work => {
+            writeln!(screen, "{}{}{}{}{}{}",
+                termion::style::Bold,
+                termion::color::Fg(termion::color::LightRed),
+                termion::cursor::Goto((state.width / 2) - (label.len() / 2) as u16, state.height / 2),
+                label,
+                termion::color::Fg(termion::color::Reset),
+                termion::style::Reset).unwrap();
+        },
+        Phase::ShortBreak => {
+            writeln!(screen, "{}{}{}{}{}{}",
+                termion::style::Bold,
+                termion::color::Fg(termion::color::LightGreen),
+                termion::cursor::Goto((state.width / 2) - (label.len() / 2) as u16, state.height / 2),
+                label,
+                termion::color::Fg(termion::color::Reset),
+                termion::style::Reset).unwrap();
+        },
+        Phase::LongBreak => {
+            writeln!(screen, "{}{}{}{}{}{}",
+                termion::style::Bold,
+                termion::color::Fg(termion::color::LightCyan),
+                termion::cursor::Goto((state.width / 2) - (label.len() / 2) as u16, state.height / 2),
+                label,
+                termion::color::Fg(termion::color::Reset),
+                termion::style::Reset).unwrap();
+        }
     }
-    
+
     // Print Start
-    println!("{}{}Start: {}{}", 
+    writeln!(screen, "{}{}Start: {}{}",
         termion::cursor::Goto(4, 2),
         termion::color::Fg(termion::color::Reset),
         termion::color::Fg(termion::color::LightBlue),
         state.start.format("%l:%M"),
-        );
+        ).unwrap();
 
     // Print Duration
     let duration_str_for_size = format!("Duration: {}m", state.duration.num_minutes());
@@ -199,10 +610,10 @@ fn draw_all(state:&State) {
         termion::color::Fg(termion::color::LightBlue),
         state.duration.num_minutes()
         );
-        
-    println!("{}{}", 
+
+    writeln!(screen, "{}{}",
         termion::cursor::Goto((state.width / 2) - (duration_str_for_size.len() / 2) as u16, 2),
-        duration_str);
+        duration_str).unwrap();
 
     // Print End
     let end_str = format!(
@@ -211,12 +622,12 @@ fn draw_all(state:&State) {
         termion::color::Fg(termion::color::LightBlue),
         state.end.format("%l:%M")
         );
-    
-    println!("{}{}", 
+
+    writeln!(screen, "{}{}",
         termion::cursor::Goto(state.width - 9 - 4 as u16, 2),
-        end_str);
-    
-    println!("{}", termion::cursor::Hide);
+        end_str).unwrap();
+
+    writeln!(screen, "{}", termion::cursor::Hide).unwrap();
 }
 
 /**
@@ -230,6 +641,29 @@ fn write_duration(dur: chrono::Duration, writer: &mut dyn std::io::Write) {
     }
 }
 
+/// Minutes rendering of `dur`, computed the same way `write_duration` renders the live
+/// countdown (whole minutes plus one above 60s) so the summary agrees with what was on
+/// screen. A duration of 60s or less renders in seconds and counts as 0 minutes.
+fn duration_minutes(dur: chrono::Duration) -> i64 {
+    let mut buf: Vec<u8> = Vec::new();
+    write_duration(dur, &mut buf);
+    let rendered = String::from_utf8(buf).unwrap();
+    let trimmed = rendered.trim();
+
+    match trimmed.strip_suffix('m') {
+        Some(minutes) => minutes.parse().unwrap_or(0),
+        None => 0
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn duration_minutes_tests() {
+    assert_eq!(0, duration_minutes(Duration::seconds(59)));
+    assert_eq!(25, duration_minutes(Duration::seconds(24 * 60 + 50)));
+    assert_eq!(2, duration_minutes(Duration::seconds(61)));
+}
+
 
 #[cfg(test)]
 #[test]
@@ -249,7 +683,7 @@ fn duration_str_tests() {
 
 fn num_bar_fill(remaining:chrono::Duration, _duration:chrono::Duration, bar_size:u16) -> u16 {
     let percent = 1.0 - (remaining.num_seconds() as f64 / _duration.num_seconds() as f64);
-    
+
     (percent * f64::from(bar_size)) as u16
 }
 
@@ -257,32 +691,78 @@ fn num_bar_fill(remaining:chrono::Duration, _duration:chrono::Duration, bar_size
 #[cfg(test)]
 #[test]
 fn num_bar_fill_tests() {
-    assert_eq!(40, num_bar_fill(Duration::seconds(30), Duration::seconds(60), 80));    
+    assert_eq!(40, num_bar_fill(Duration::seconds(30), Duration::seconds(60), 80));
 }
 
-fn draw_changes(state: &State) {
-    print!("{}{}Remaining: {}", 
+fn draw_changes(state: &State, screen: &mut dyn Write) {
+    let remaining_label = if state.paused_at.is_some() { "PAUSED " } else { "Remaining: " };
+
+    write!(screen, "{}{}{}{}",
         termion::cursor::Goto(4, state.height - 3),
         termion::color::Fg(termion::color::Reset),
-        termion::color::Fg(termion::color::LightBlue));
-    write_duration(state.remaining, &mut std::io::stdout().lock());
+        termion::color::Fg(termion::color::LightBlue),
+        remaining_label).unwrap();
+    write_duration(state.remaining, screen);
 
     //    duration_str(remaining));
 
     let bar_size = state.width - 4 - 4;
     let progress_current = num_bar_fill(state.remaining, state.duration, state.width);
 
-     // TODO pull out the string / percent 
-    print!("{}", termion::color::Bg(termion::color::Blue));
+     // TODO pull out the string / percent
+    match state.phase {
+        Phase::Work => write!(screen, "{}", termion::color::Bg(termion::color::Blue)).unwrap(),
+        Phase::ShortBreak => write!(screen, "{}", termion::color::Bg(termion::color::Green)).unwrap(),
+        Phase::LongBreak => write!(screen, "{}", termion::color::Bg(termion::color::Cyan)).unwrap()
+    }
     for c in 4..(4+progress_current) {
-        print!("{} ", termion::cursor::Goto(c, state.height - 1))
+        write!(screen, "{} ", termion::cursor::Goto(c, state.height - 1)).unwrap();
     }
-    print!("{}", termion::color::Bg(termion::color::Reset));
+    write!(screen, "{}", termion::color::Bg(termion::color::Reset)).unwrap();
 
-    print!("{}", termion::color::Bg(termion::color::White));
+    write!(screen, "{}", termion::color::Bg(termion::color::White)).unwrap();
     for c in (4 + progress_current)..(bar_size+4) {
-        print!("{} ", termion::cursor::Goto(c, state.height - 1))
+        write!(screen, "{} ", termion::cursor::Goto(c, state.height - 1)).unwrap();
+    }
+
+    writeln!(screen, "{}", termion::color::Bg(termion::color::Reset)).unwrap();
+
+    let _ = screen.flush();
+}
+
+fn print_summary(log_path: &Path) {
+    let contents = fs::read_to_string(log_path).unwrap_or_default();
+    let today = Local::now().format("%Y-%m-%d").to_string();
+
+    let mut focused_minutes = 0i64;
+    let mut completed_pomodoros = 0u32;
+    let mut minutes_per_task: HashMap<String, i64> = HashMap::new();
+
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.splitn(5, ',').collect();
+        if fields.len() < 5 {
+            continue;
+        }
+
+        let (start, _end, duration_seconds, phase, task) = (fields[0], fields[1], fields[2], fields[3], fields[4]);
+
+        if !start.starts_with(&today) || phase != phase_name(&Phase::Work) {
+            continue;
+        }
+
+        let minutes = duration_minutes(Duration::seconds(duration_seconds.parse().unwrap_or(0)));
+        focused_minutes += minutes;
+        completed_pomodoros += 1;
+
+        let task_label = if task.is_empty() { "(no task)" } else { task };
+        *minutes_per_task.entry(task_label.to_string()).or_insert(0) += minutes;
     }
 
-    println!("{}", termion::color::Bg(termion::color::Reset));
-}
\ No newline at end of file
+    println!("Focused minutes today: {}", focused_minutes);
+    println!("Completed work pomodoros today: {}", completed_pomodoros);
+    println!("Time per task:");
+
+    for (task, minutes) in &minutes_per_task {
+        println!("  {}: {}m", task, minutes);
+    }
+}